@@ -1,42 +1,138 @@
+use std::collections::HashSet;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufReader, Write};
+use std::io::{BufReader, BufWriter, Write};
 use std::path::Path;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime, TimeZone, Weekday};
+use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
+// This function tells whether terminal output should be colorized.
+// Respects the NO_COLOR convention; output is kept plain only when NO_COLOR
+// is set, redirected/piped output is not detected on its own
+fn colors_enabled() -> bool {
+    std::env::var_os("NO_COLOR").is_none()
+}
+
 // Task priority enumeration
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 enum Priority {
     Low,
     Medium,
     High,
 }
 
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        };
+        write!(f, "{}", text)
+    }
+}
+
 impl Priority {
-    // This method returns a string variant of enum variant
-    // In this case i can use just a debug token in format, but
-    // this method was created for more flexibility
-    fn to_string(&self) -> String {
+    // This method renders the priority in a distinct color, falling back
+    // to the plain text when colors are disabled
+    fn colored(&self) -> String {
+        if !colors_enabled() {
+            return self.to_string();
+        }
+
         match self {
-            Priority::Low => "Low".to_owned(),
-            Priority::Medium => "Medium".to_owned(),
-            Priority::High => "High".to_owned()
+            Priority::Low => self.to_string().green().to_string(),
+            Priority::Medium => self.to_string().yellow().to_string(),
+            Priority::High => self.to_string().red().to_string(),
         }
     }
 }
 
-#[derive(Serialize, Deserialize)]
+// Task status enumeration
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy)]
+enum Status {
+    Todo,
+    Active,
+    Done,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Status::Todo => "Todo",
+            Status::Active => "Active",
+            Status::Done => "Done",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+// A single logged chunk of time spent working on a task
+#[derive(Serialize, Deserialize, Clone)]
+struct TimeEntry {
+    logged_date: DateTime<Local>,
+    hours: u32,
+    minutes: u32,
+}
+
+impl TimeEntry {
+    // This method creates new TimeEntry, normalizing minute overflow
+    // (e.g. 90 minutes becomes 1h30m) into the hours field
+    fn new(hours: u32, minutes: u32) -> Self {
+        Self {
+            logged_date: Local::now(),
+            hours: hours + minutes / 60,
+            minutes: minutes % 60,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 struct Task {
     name: String,
     description: String,
     priority: Priority,
     add_time: DateTime<Local>,
+    deadline: Option<DateTime<Local>>,
+    status: Status,
+    started_at: Option<DateTime<Local>>,
+    completed_at: Option<DateTime<Local>>,
+    tags: HashSet<String>,
+    // Display indices (as shown by print_tasks) of the tasks this one depends on
+    dependencies: Vec<usize>,
+    time_entries: Vec<TimeEntry>,
 }
 
 impl Task {
     // This method creates new Task object from given parameters
-    fn new(name: String, description: String, priority: Priority) -> Self {
-        Self { name, description, priority, add_time: Local::now() }
+    fn new(
+        name: String,
+        description: String,
+        priority: Priority,
+        deadline: Option<DateTime<Local>>,
+        tags: HashSet<String>,
+        dependencies: Vec<usize>,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            priority,
+            add_time: Local::now(),
+            deadline,
+            status: Status::Todo,
+            started_at: None,
+            completed_at: None,
+            tags,
+            dependencies,
+            time_entries: vec![],
+        }
+    }
+
+    // This method sums up all logged time entries into a total (hours, minutes) pair
+    fn total_time_spent(&self) -> (u32, u32) {
+        let total_minutes: u32 = self.time_entries.iter().map(|entry| entry.hours * 60 + entry.minutes).sum();
+        (total_minutes / 60, total_minutes % 60)
     }
 
     // This method creates new Task object from user input data
@@ -70,19 +166,208 @@ impl Task {
                 return None;
             }
         };
+        let deadline = match ConsoleManager::input("Enter new task deadline (e.g. \"tomorrow\", \"next friday 5pm\", \"in 3 days\", leave empty for none): ") {
+            Ok(input) => Self::parse_deadline(&input),
+            Err(err) => {
+                println!("Error getting user input: {}", err);
+                None
+            }
+        };
+        let tags = match ConsoleManager::input("Enter task tags (comma-separated, leave empty for none): ") {
+            Ok(input) => Self::parse_tags(&input),
+            Err(err) => {
+                println!("Error getting user input: {}", err);
+                HashSet::new()
+            }
+        };
+        let dependencies = match ConsoleManager::input("Enter indices of tasks this one depends on (comma-separated, leave empty for none): ") {
+            Ok(input) => Self::parse_dependencies(&input),
+            Err(err) => {
+                println!("Error getting user input: {}", err);
+                vec![]
+            }
+        };
+
+        Some(Self::new(name, description, priority, deadline, tags, dependencies))
+    }
+
+    // This method parses a comma-separated list of tags
+    fn parse_tags(input: &str) -> HashSet<String> {
+        input.split(',').map(|tag| tag.trim().to_owned()).filter(|tag| !tag.is_empty()).collect()
+    }
+
+    // This method parses a comma-separated list of dependency display indices
+    fn parse_dependencies(input: &str) -> Vec<usize> {
+        input.split(',').filter_map(|id| id.trim().parse::<usize>().ok()).collect()
+    }
+
+    // This method leniently parses a deadline from free-form user input.
+    // It recognizes "today"/"tomorrow", weekday names (optionally prefixed
+    // with "next"), a leading "in N days/weeks/hours" offset from now, and
+    // falls back to strict "%d-%m-%Y %H:%M" parsing. Returns None if the
+    // input is empty or doesn't match any of these shapes.
+    fn parse_deadline(input: &str) -> Option<DateTime<Local>> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        let lower = input.to_lowercase();
+
+        if lower == "today" {
+            let ndt = Local::now().date_naive().and_hms_opt(0, 0, 0)?;
+            return Local.from_local_datetime(&ndt).single();
+        }
+        if lower == "tomorrow" {
+            let ndt = (Local::now().date_naive() + Duration::days(1)).and_hms_opt(0, 0, 0)?;
+            return Local.from_local_datetime(&ndt).single();
+        }
+
+        let weekdays = [
+            ("monday", Weekday::Mon), ("tuesday", Weekday::Tue), ("wednesday", Weekday::Wed),
+            ("thursday", Weekday::Thu), ("friday", Weekday::Fri), ("saturday", Weekday::Sat),
+            ("sunday", Weekday::Sun),
+        ];
+        let without_next = lower.strip_prefix("next ").unwrap_or(lower.as_str());
+        for (name, weekday) in weekdays {
+            if let Some(rest) = without_next.strip_prefix(name) {
+                let mut date = Local::now().date_naive() + Duration::days(1);
+                while date.weekday() != weekday {
+                    date += Duration::days(1);
+                }
+                let (hour, minute) = Self::parse_time_of_day(rest.trim()).unwrap_or((0, 0));
+                let ndt = date.and_hms_opt(hour, minute, 0)?;
+                return Local.from_local_datetime(&ndt).single();
+            }
+        }
+
+        if let Some(rest) = lower.strip_prefix("in ") {
+            let parts: Vec<&str> = rest.split_whitespace().collect();
+            if parts.len() >= 2 {
+                if let Ok(amount) = parts[0].parse::<i64>() {
+                    let duration = match parts[1].trim_end_matches('s') {
+                        "day" => Some(Duration::days(amount)),
+                        "week" => Some(Duration::weeks(amount)),
+                        "hour" => Some(Duration::hours(amount)),
+                        _ => None,
+                    };
+                    if let Some(duration) = duration {
+                        return Some(Local::now() + duration);
+                    }
+                }
+            }
+        }
+
+        NaiveDateTime::parse_from_str(input, "%d-%m-%Y %H:%M")
+            .ok()
+            .and_then(|ndt| Local.from_local_datetime(&ndt).single())
+    }
+
+    // This method parses a trailing time-of-day like "5pm", "5:30pm" or "17:00"
+    fn parse_time_of_day(input: &str) -> Option<(u32, u32)> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+
+        if let Some(stripped) = input.strip_suffix("pm").or_else(|| input.strip_suffix("am")) {
+            let is_pm = input.ends_with("pm");
+            let mut parts = stripped.splitn(2, ':');
+            let mut hour: u32 = parts.next()?.trim().parse().ok()?;
+            let minute: u32 = parts.next().map(|m| m.trim().parse().unwrap_or(0)).unwrap_or(0);
+            if is_pm && hour < 12 {
+                hour += 12;
+            }
+            if !is_pm && hour == 12 {
+                hour = 0;
+            }
+            return Some((hour, minute));
+        }
 
-        Some(Self::new(name, description, priority))
+        let mut parts = input.splitn(2, ':');
+        let hour: u32 = parts.next()?.trim().parse().ok()?;
+        let minute: u32 = parts.next()?.trim().parse().ok()?;
+        Some((hour, minute))
     }
 
-    // This method simply prints task information
-    fn print_task(&self) {
-        println!(
-            "{} | {} | {}\n\"{}\"\n",
+    // This method simply prints task information, prefixed with its
+    // 1-based display id (e.g. "1.") so it can be referenced by index
+    fn print_task(&self, id: usize) {
+        // A done task is printed fully dimmed as a single styling pass, so no
+        // other segment gets its own color wrap nested inside that pass
+        let colorize_segments = colors_enabled() && self.status != Status::Done;
+
+        let deadline_info = match &self.deadline {
+            Some(deadline) => {
+                let overdue = *deadline < Local::now();
+                let text = format!(
+                    " | deadline: {}{}",
+                    deadline.format("%d-%m-%Y %H:%M"),
+                    if overdue { " (OVERDUE)" } else { "" }
+                );
+                if overdue && colorize_segments { text.red().to_string() } else { text }
+            }
+            None => String::new(),
+        };
+
+        let tags_info = if self.tags.is_empty() {
+            String::new()
+        } else {
+            let mut tags: Vec<&String> = self.tags.iter().collect();
+            tags.sort();
+            format!(" | tags: {}", tags.iter().map(|tag| tag.as_str()).collect::<Vec<_>>().join(", "))
+        };
+
+        let dependencies_info = if self.dependencies.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " | depends on: {}",
+                self.dependencies.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ")
+            )
+        };
+
+        let time_info = if self.time_entries.is_empty() {
+            String::new()
+        } else {
+            let (hours, minutes) = self.total_time_spent();
+            format!(" | time spent: {}h{}m", hours, minutes)
+        };
+
+        let started_info = match self.started_at {
+            Some(started_at) => format!(" | started: {}", started_at.format("%d-%m-%Y %H:%M:%S")),
+            None => String::new(),
+        };
+
+        let completed_info = match self.completed_at {
+            Some(completed_at) => format!(" | completed: {}", completed_at.format("%d-%m-%Y %H:%M:%S")),
+            None => String::new(),
+        };
+
+        let priority_info = if colorize_segments { self.priority.colored() } else { self.priority.to_string() };
+
+        let line = format!(
+            "{}. {} | {} | {} | {}{}{}{}{}{}{}\n\"{}\"\n",
+            id,
             self.name,
-            self.priority.to_string(),
+            self.status,
+            priority_info,
             self.add_time.format("%d-%m-%Y %H:%M:%S"),
+            deadline_info,
+            tags_info,
+            dependencies_info,
+            time_info,
+            started_info,
+            completed_info,
             self.description
-        )
+        );
+
+        if self.status == Status::Done && colors_enabled() {
+            // The whole line is plain text at this point (colorize_segments was
+            // false above), so this is the line's only styling pass
+            println!("{}", line.dimmed());
+        } else {
+            println!("{}", line);
+        }
     }
 }
 
@@ -97,16 +382,108 @@ impl TasksManager {
         Self { tasks: vec![] }
     }
 
-    // This method prints every added task info
-    fn print_tasks(&self) {
-        for task in &self.tasks {
-            task.print_task();
+    // This method prints tasks grouped by status; done tasks are only
+    // included when include_done is set, since they clutter the default view
+    fn print_tasks(&self, include_done: bool) {
+        self.print_tasks_with_status(Status::Active, "Active");
+        self.print_tasks_with_status(Status::Todo, "Todo");
+        if include_done {
+            self.print_tasks_with_status(Status::Done, "Done");
+        }
+    }
+
+    // This method prints every task with the given status under a header
+    fn print_tasks_with_status(&self, status: Status, label: &str) {
+        let matching: Vec<(usize, &Task)> = self.tasks.iter().enumerate().filter(|(_, task)| task.status == status).collect();
+        if matching.is_empty() {
+            return;
+        }
+
+        println!("-- {} --", label);
+        for (index, task) in matching {
+            task.print_task(index + 1);
         }
     }
 
-    // This method adds given task to the tasks vector
-    fn add_task(&mut self, task: Task) {
+    // This method adds given task to the tasks vector, refusing it if its
+    // dependencies would introduce a cycle into the dependency graph
+    fn add_task(&mut self, task: Task) -> Result<String, String> {
         self.tasks.push(task);
+
+        if let Err(err) = self.check_dependency_cycles() {
+            self.tasks.pop();
+            return Err(err);
+        }
+
+        Ok("Task added successfully".to_owned())
+    }
+
+    // This method returns the tasks (with their 0-based position in the tasks vector) carrying the given tag
+    fn filter_by_tag(&self, tag: &str) -> Vec<(usize, &Task)> {
+        self.tasks.iter().enumerate().filter(|(_, task)| task.tags.contains(tag)).collect()
+    }
+
+    // This method logs a chunk of time against the task with given name or display index
+    fn log_time(&mut self, identifier: &str, hours: u32, minutes: u32) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+        let task = &mut self.tasks[index];
+        task.time_entries.push(TimeEntry::new(hours, minutes));
+        Ok(format!("Logged time for task \"{}\"", task.name))
+    }
+
+    // This method sums up the logged time across every task
+    fn total_time_spent(&self) -> (u32, u32) {
+        let total_minutes: u32 = self.tasks.iter()
+            .flat_map(|task| &task.time_entries)
+            .map(|entry| entry.hours * 60 + entry.minutes)
+            .sum();
+        (total_minutes / 60, total_minutes % 60)
+    }
+
+    // This method returns the display index of the first unfinished dependency
+    // of the task at the given position, if any
+    fn unfinished_dependency(&self, index: usize) -> Option<usize> {
+        self.tasks[index].dependencies.iter().copied().find(|&dependency_id| {
+            match self.find_by_index(dependency_id) {
+                Some(dependency_index) => self.tasks[dependency_index].status != Status::Done,
+                None => false,
+            }
+        })
+    }
+
+    // This method walks the whole dependency graph with a simple DFS and
+    // errors as soon as it finds a back-edge, which means a cycle
+    fn check_dependency_cycles(&self) -> Result<(), String> {
+        let mut state = vec![0u8; self.tasks.len()];
+
+        for index in 0..self.tasks.len() {
+            if state[index] == 0 {
+                self.walk_dependency_cycle(index, &mut state)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 0 = unvisited, 1 = visiting (on the current DFS path), 2 = done
+    fn walk_dependency_cycle(&self, index: usize, state: &mut Vec<u8>) -> Result<(), String> {
+        state[index] = 1;
+
+        for &dependency_id in &self.tasks[index].dependencies {
+            if let Some(dependency_index) = self.find_by_index(dependency_id) {
+                match state[dependency_index] {
+                    1 => return Err(format!(
+                        "Dependency cycle detected: \"{}\" depends on \"{}\"",
+                        self.tasks[index].name, self.tasks[dependency_index].name
+                    )),
+                    0 => self.walk_dependency_cycle(dependency_index, state)?,
+                    _ => {}
+                }
+            }
+        }
+
+        state[index] = 2;
+        Ok(())
     }
 
     // This method searches for the task with given name in the added tasks vector
@@ -114,48 +491,159 @@ impl TasksManager {
         self.tasks.iter().position(|task| task.name == name)
     }
 
-    // This method deletes task with the given task name from the added tasks vector
-    fn remove_task(&mut self, name: &str) -> Result<String, String> {
-        if let Some(index) = self.find_task(name) {
-            self.tasks.remove(index);
-            Ok(format!("Task \"{}\" removed successfully", name))
+    // This method resolves the 1-based display id (as shown by print_tasks)
+    // back to a position in the tasks vector
+    fn find_by_index(&self, id: usize) -> Option<usize> {
+        if id >= 1 && id <= self.tasks.len() {
+            Some(id - 1)
         } else {
-            Err(format!("Task with name \"{}\" doesn't exist", name))
+            None
+        }
+    }
+
+    // This method resolves a user-typed identifier that may be either a
+    // numeric display id or a task name, so commands can accept both
+    fn find_by_identifier(&self, identifier: &str) -> Option<usize> {
+        match identifier.parse::<usize>() {
+            Ok(id) => self.find_by_index(id),
+            Err(_) => self.find_task(identifier),
+        }
+    }
+
+    // This method marks the task with the given name as active, starting
+    // its timer. Only one task may be active at a time
+    fn start_task(&mut self, identifier: &str) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+        let name = self.tasks[index].name.clone();
+
+        if let Some(active) = self.tasks.iter().find(|task| task.status == Status::Active) {
+            if active.name == name {
+                return Err(format!("Task \"{}\" is already active", name));
+            }
+
+            return Err(format!(
+                "Can't start task \"{}\": task \"{}\" is already active",
+                name, active.name
+            ));
+        }
+
+        let task = &mut self.tasks[index];
+        if task.status == Status::Done {
+            return Err(format!("Task \"{}\" is already done", name));
         }
+
+        task.status = Status::Active;
+        task.started_at = Some(Local::now());
+        Ok(format!("Task \"{}\" started", name))
     }
 
-    // This method searches for task with given name and updates its fields
-    fn edit_task(&mut self, name: &str, updated_task: Task) -> Result<String, String> {
-        if let Some(index) = self.find_task(name) {
-            match self.tasks.get_mut(index) {
-                None => Err("Error fetching task".to_owned()),
-                Some(task) => {
-                    task.name = updated_task.name;
-                    task.description = updated_task.description;
-                    task.priority = updated_task.priority;
+    // This method stops the active task with the given name or display index, returning it to Todo
+    fn stop_task(&mut self, identifier: &str) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+        let task = &mut self.tasks[index];
+        if task.status != Status::Active {
+            return Err(format!("Task \"{}\" isn't active", task.name));
+        }
+
+        task.status = Status::Todo;
+        Ok(format!("Task \"{}\" stopped", task.name))
+    }
 
-                    Ok(format!("Task \"{}\" updated successfully", name))
+    // This method marks the task with the given name or display index as done
+    fn complete_task(&mut self, identifier: &str) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+        if let Some(dependency_id) = self.unfinished_dependency(index) {
+            return Err(format!(
+                "Can't complete task \"{}\": dependency {} isn't done yet",
+                self.tasks[index].name, dependency_id
+            ));
+        }
+
+        let task = &mut self.tasks[index];
+        if task.status == Status::Done {
+            return Err(format!("Task \"{}\" is already done", task.name));
+        }
+
+        task.status = Status::Done;
+        task.completed_at = Some(Local::now());
+        Ok(format!("Task \"{}\" completed", task.name))
+    }
+
+    // This method deletes task with the given name or display index from the added tasks vector
+    fn remove_task(&mut self, identifier: &str) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+        if let Some(dependency_id) = self.unfinished_dependency(index) {
+            return Err(format!(
+                "Can't remove task \"{}\": dependency {} isn't done yet",
+                self.tasks[index].name, dependency_id
+            ));
+        }
+
+        let removed_id = index + 1;
+        if self.tasks[index].status != Status::Done {
+            if let Some(dependent) = self.tasks.iter().find(|task| task.dependencies.contains(&removed_id)) {
+                return Err(format!(
+                    "Can't remove task \"{}\": task \"{}\" still depends on it and it isn't done yet",
+                    self.tasks[index].name, dependent.name
+                ));
+            }
+        }
+
+        let name = self.tasks.remove(index).name;
+        self.remap_dependencies_after_removal(removed_id);
+
+        Ok(format!("Task \"{}\" removed successfully", name))
+    }
+
+    // Removing a task shifts every later task's display index down by one,
+    // so every stored dependency referencing those indices must shift too.
+    // A dependency pointing at the removed task itself is only dropped here
+    // because remove_task already refused the removal unless that task was Done
+    fn remap_dependencies_after_removal(&mut self, removed_id: usize) {
+        for task in &mut self.tasks {
+            task.dependencies.retain(|&dependency_id| dependency_id != removed_id);
+            for dependency_id in task.dependencies.iter_mut() {
+                if *dependency_id > removed_id {
+                    *dependency_id -= 1;
                 }
             }
-        } else {
-            Err(format!("Task with name \"{}\" doesn't exist", name))
         }
     }
 
+    // This method searches for task with given name or display index and updates its fields
+    // Like add_task, this guards against edits that would introduce a
+    // dependency cycle, reverting the whole task back on failure
+    fn edit_task(&mut self, identifier: &str, updated_task: Task) -> Result<String, String> {
+        let index = self.find_by_identifier(identifier).ok_or_else(|| format!("Task \"{}\" doesn't exist", identifier))?;
+
+        let previous_task = self.tasks[index].clone();
+        let task = &mut self.tasks[index];
+        task.name = updated_task.name;
+        task.description = updated_task.description;
+        task.priority = updated_task.priority;
+        task.deadline = updated_task.deadline;
+        task.tags = updated_task.tags;
+        task.dependencies = updated_task.dependencies;
+
+        if let Err(err) = self.check_dependency_cycles() {
+            self.tasks[index] = previous_task;
+            return Err(err);
+        }
+
+        Ok(format!("Task \"{}\" updated successfully", self.tasks[index].name))
+    }
+
     // This method stores tasks list to the file in json format
     fn store_to_file(&self, filename: &str) -> Result<String, String> {
-        if !Path::new(filename).exists() {
-            let file = match File::create(filename) {
-                Ok(file) => file,
-                Err(err) => return Err(format!("Error creating file: {}", err))
-            };
+        let file = match File::create(filename) {
+            Ok(file) => file,
+            Err(err) => return Err(format!("Error creating file: {}", err))
+        };
+        let writer = BufWriter::new(file);
 
-            match serde_json::to_writer(&file, &self.tasks) {
-                Ok(_) => Ok("Data stored successfully".to_owned()),
-                Err(err) => Err(format!("Error saving data: {}", err))
-            }
-        } else {
-            Err("File \"{filename}\" already exists".to_owned())
+        match serde_json::to_writer_pretty(writer, &self.tasks) {
+            Ok(_) => Ok("Data stored successfully".to_owned()),
+            Err(err) => Err(format!("Error saving data: {}", err))
         }
     }
 
@@ -181,6 +669,36 @@ impl TasksManager {
             Err(format!("File \"{}\" doesn't exist", filename))
         }
     }
+
+    // This method loads the existing tasks from the file (if any), merges
+    // the current in-memory tasks into them by name (updating tasks that
+    // already exist there and adding the ones that don't), keeps the result
+    // as the current task list, and writes it back to the file
+    fn sync_to_file(&mut self, filename: &str) -> Result<String, String> {
+        let mut merged: Vec<Task> = if Path::new(filename).exists() {
+            let file = match File::open(filename) {
+                Ok(file) => file,
+                Err(err) => return Err(format!("Error opening file: {}", err))
+            };
+
+            match serde_json::from_reader(BufReader::new(file)) {
+                Ok(data) => data,
+                Err(err) => return Err(format!("Error reading file: {}", err))
+            }
+        } else {
+            vec![]
+        };
+
+        for task in self.tasks.drain(..) {
+            match merged.iter().position(|existing| existing.name == task.name) {
+                Some(index) => merged[index] = task,
+                None => merged.push(task),
+            }
+        }
+        self.tasks = merged;
+
+        self.store_to_file(filename)
+    }
 }
 
 struct ConsoleManager {
@@ -198,9 +716,17 @@ impl ConsoleManager {
                 "Find task".to_owned(),
                 "Edit task".to_owned(),
                 "Remove task".to_owned(),
+                "Start task".to_owned(),
+                "Stop task".to_owned(),
+                "Complete task".to_owned(),
                 "Print tasks".to_owned(),
+                "Print tasks (include done)".to_owned(),
                 "Store tasks to file".to_owned(),
                 "Read tasks from file".to_owned(),
+                "List tasks by tag".to_owned(),
+                "Sync tasks with file".to_owned(),
+                "Log time for task".to_owned(),
+                "Show total time spent".to_owned(),
             ],
         }
     }
@@ -231,34 +757,37 @@ impl ConsoleManager {
                 match command.as_str() {
                     "1" => {
                         if let Some(task) = Task::new_from_console() {
-                            self.tasks_manager.add_task(task);
+                            match self.tasks_manager.add_task(task) {
+                                Ok(msg) => println!("{}", msg),
+                                Err(msg) => println!("{}", msg),
+                            }
                         }
                     }
 
                     "2" => {
-                        let name = match Self::input("Enter task name to find: ") {
-                            Ok(name) => name,
+                        let identifier = match Self::input("Enter task name or index to find: ") {
+                            Ok(identifier) => identifier,
                             Err(err) => {
                                 println!("Error getting user input: {}", err);
                                 return;
                             }
                         };
 
-                        match self.tasks_manager.find_task(name.as_str()) {
-                            None => println!("Task with name \"{}\" doesn't exist", name),
+                        match self.tasks_manager.find_by_identifier(identifier.as_str()) {
+                            None => println!("Task \"{}\" doesn't exist", identifier),
                             Some(index) => {
                                 println!("Task found!");
 
                                 match self.tasks_manager.tasks.get(index) {
                                     None => println!("Error fetching task"),
-                                    Some(task) => task.print_task()
+                                    Some(task) => task.print_task(index + 1)
                                 }
                             }
                         }
                     }
 
                     "3" => {
-                        let name = match Self::input("Enter task name to edit: ") {
+                        let name = match Self::input("Enter task name or index to edit: ") {
                             Ok(name) => name,
                             Err(err) => {
                                 println!("Error getting user input: {}", err);
@@ -275,7 +804,7 @@ impl ConsoleManager {
                     }
 
                     "4" => {
-                        let name = match Self::input("Enter task name to remove: ") {
+                        let name = match Self::input("Enter task name or index to remove: ") {
                             Ok(name) => name,
                             Err(err) => {
                                 println!("Error getting user input: {}", err);
@@ -290,10 +819,59 @@ impl ConsoleManager {
                     }
 
                     "5" => {
-                        self.tasks_manager.print_tasks();
+                        let name = match Self::input("Enter task name or index to start: ") {
+                            Ok(name) => name,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        match self.tasks_manager.start_task(name.as_str()) {
+                            Ok(msg) => println!("{}", msg),
+                            Err(msg) => println!("{}", msg),
+                        }
                     }
 
                     "6" => {
+                        let name = match Self::input("Enter task name or index to stop: ") {
+                            Ok(name) => name,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        match self.tasks_manager.stop_task(name.as_str()) {
+                            Ok(msg) => println!("{}", msg),
+                            Err(msg) => println!("{}", msg),
+                        }
+                    }
+
+                    "7" => {
+                        let name = match Self::input("Enter task name or index to complete: ") {
+                            Ok(name) => name,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        match self.tasks_manager.complete_task(name.as_str()) {
+                            Ok(msg) => println!("{}", msg),
+                            Err(msg) => println!("{}", msg),
+                        }
+                    }
+
+                    "8" => {
+                        self.tasks_manager.print_tasks(false);
+                    }
+
+                    "9" => {
+                        self.tasks_manager.print_tasks(true);
+                    }
+
+                    "10" => {
                         let filename = match Self::input("Enter file name to store data in: ") {
                             Ok(filename) => filename,
                             Err(err) => {
@@ -308,7 +886,7 @@ impl ConsoleManager {
                         }
                     }
 
-                    "7" => {
+                    "11" => {
                         let filename = match Self::input("Enter file name to read data from: ") {
                             Ok(filename) => filename,
                             Err(err) => {
@@ -323,6 +901,74 @@ impl ConsoleManager {
                         }
                     }
 
+                    "12" => {
+                        let tag = match Self::input("Enter tag to filter by: ") {
+                            Ok(tag) => tag,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        let matching = self.tasks_manager.filter_by_tag(tag.as_str());
+                        if matching.is_empty() {
+                            println!("No tasks with tag \"{}\"", tag);
+                        } else {
+                            for (index, task) in matching {
+                                task.print_task(index + 1);
+                            }
+                        }
+                    }
+
+                    "13" => {
+                        let filename = match Self::input("Enter file name to sync data with: ") {
+                            Ok(filename) => filename,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        match self.tasks_manager.sync_to_file(filename.as_str()) {
+                            Ok(msg) => println!("{}", msg),
+                            Err(msg) => println!("{}", msg),
+                        }
+                    }
+
+                    "14" => {
+                        let identifier = match Self::input("Enter task name or index to log time for: ") {
+                            Ok(identifier) => identifier,
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+                        let hours = match Self::input("Enter hours spent: ") {
+                            Ok(input) => input.parse::<u32>().unwrap_or(0),
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+                        let minutes = match Self::input("Enter minutes spent: ") {
+                            Ok(input) => input.parse::<u32>().unwrap_or(0),
+                            Err(err) => {
+                                println!("Error getting user input: {}", err);
+                                return;
+                            }
+                        };
+
+                        match self.tasks_manager.log_time(identifier.as_str(), hours, minutes) {
+                            Ok(msg) => println!("{}", msg),
+                            Err(msg) => println!("{}", msg),
+                        }
+                    }
+
+                    "15" => {
+                        let (hours, minutes) = self.tasks_manager.total_time_spent();
+                        println!("Total time spent: {}h{}m", hours, minutes);
+                    }
+
                     _ => println!("I don't understand this command :(")
                 }
             }